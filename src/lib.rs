@@ -1,17 +1,83 @@
-use pyo3::prelude::*;
+use std::path::Path;
+
+use pyo3::{
+    exceptions::{PySystemExit, PyValueError},
+    prelude::*,
+};
 
 mod schema;
 
+const USAGE: &str = "usage: recad convert <input> <output>";
+
 /// recad main function.
+///
+/// Runs the command given on the command line:
+///
+///     recad convert <input> <output>
+///
+/// Usage errors exit with code 2, failed commands with code 1.
 #[pyfunction]
-pub fn main() -> PyResult<()> {
-    env_logger::init();
-    Ok(())
+pub fn main(py: Python<'_>) -> PyResult<()> {
+    let _ = env_logger::try_init();
+    let args: Vec<String> = py.import_bound("sys")?.getattr("argv")?.extract()?;
+    match args.get(1).map(String::as_str) {
+        Some("-h") | Some("--help") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some("convert") if args.len() == 4 => {
+            convert(&args[2], &args[3]).or_else(|err| exit(&err.value_bound(py).to_string(), 1))
+        }
+        Some("convert") | None => exit(USAGE, 2),
+        Some(command) => exit(&format!("unknown command '{}'\n{}", command, USAGE), 2),
+    }
+}
+
+/// Print the message to stderr and exit with the code.
+fn exit(message: &str, code: i32) -> PyResult<()> {
+    eprintln!("recad: {}", message);
+    Err(PyErr::new::<PySystemExit, _>(code))
+}
+
+/// Convert a schema file.
+///
+/// The output format is selected by the file extension of
+/// the output path. Supported is `kicad_sch` input and `svg`
+/// output, plotted with the KiCad 2020 theme.
+///
+/// :param input: the schema file path
+/// :param output: the output file path
+#[pyfunction]
+pub fn convert(input: &str, output: &str) -> PyResult<()> {
+    if !has_extension(input, "kicad_sch") {
+        return Err(PyErr::new::<PyValueError, _>(format!(
+            "unsupported input file '{}'",
+            input
+        )));
+    }
+    if !has_extension(output, "svg") {
+        return Err(PyErr::new::<PyValueError, _>(format!(
+            "unsupported output format '{}'",
+            output
+        )));
+    }
+
+    let schema = schema::Schema::load(input)?;
+    schema::plot_svg_file(&schema.schema, output)
+}
+
+/// Check the file extension, ignoring the case.
+fn has_extension(path: &str, extension: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case(extension))
 }
 
 #[pymodule]
 fn recad(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(main, m)?)?;
+    m.add_function(wrap_pyfunction!(convert, m)?)?;
     m.add_class::<schema::GlobalLabel>()?;
     m.add_class::<schema::Junction>()?;
     m.add_class::<schema::LocalLabel>()?;
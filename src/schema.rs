@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{io::Write, path::Path};
 
 use pyo3::{
     exceptions::PyIOError,
@@ -38,13 +38,12 @@ impl Schema {
     /// :param path: the file path
     #[staticmethod]
     pub fn load(path: &str) -> PyResult<Schema> {
-        if let Ok(s) = recad_core::Schema::load(Path::new(path)) {
-            Ok(Schema { schema: s })
-        } else {
-            Err(PyErr::new::<PyIOError, _>(format!(
-                "unable to open schema file '{}'",
-                path
-            )))
+        match recad_core::Schema::load(Path::new(path)) {
+            Ok(s) => Ok(Schema { schema: s }),
+            Err(err) => Err(PyErr::new::<PyIOError, _>(format!(
+                "unable to load schema file '{}': {}",
+                path, err
+            ))),
         }
     }
 
@@ -73,16 +72,12 @@ impl Schema {
             }
         }
 
-        let mut svg = recad_core::plot::SvgPlotter::new(); //TODO select plotter
-        self.schema.plot(&mut svg, &Theme::from(Themes::Kicad2020)); //TODO select theme
-
         Ok(if let Some(path) = path {
-            let mut file = std::fs::File::create(path).unwrap();
-            svg.write(&mut file).unwrap();
+            plot_svg_file(&self.schema, &path)?;
             None
         } else {
             let mut buffer = Vec::new();
-            svg.write(&mut buffer).unwrap();
+            plot_svg(&self.schema, &mut buffer)?;
 
             let res = Python::with_gil(|py| {
                 let svg_path: Py<PyAny> = py
@@ -102,6 +97,19 @@ impl Schema {
         })
     }
 
+    /// The bounding box of the schema content.
+    ///
+    /// :returns: the tuple ``(x, y, width, height)`` in mm.
+    pub fn bounds(&self) -> PyResult<(f32, f32, f32, f32)> {
+        let outline = self.schema.outline();
+        Ok((
+            outline.start.x,
+            outline.start.y,
+            outline.end.x - outline.start.x,
+            outline.end.y - outline.start.y,
+        ))
+    }
+
     pub fn move_to(mut instance: PyRefMut<'_, Self>, item: (f32, f32)) -> PyRefMut<'_, Self> {
         instance.schema.move_to(At::Pt(Pt {
             x: item.0,
@@ -191,6 +199,22 @@ impl Schema {
     }
 }
 
+/// Plot the schema as SVG to the writer.
+pub(crate) fn plot_svg<W: Write>(schema: &recad_core::Schema, writer: &mut W) -> PyResult<()> {
+    let mut svg = recad_core::plot::SvgPlotter::new(); //TODO select plotter
+    schema.plot(&mut svg, &Theme::from(Themes::Kicad2020)); //TODO select theme
+    svg.write(writer)
+        .map_err(|err| PyErr::new::<PyIOError, _>(format!("unable to write svg: {}", err)))
+}
+
+/// Plot the schema as SVG to the file at `path`.
+pub(crate) fn plot_svg_file(schema: &recad_core::Schema, path: &str) -> PyResult<()> {
+    let mut file = std::fs::File::create(path).map_err(|err| {
+        PyErr::new::<PyIOError, _>(format!("unable to create file '{}': {}", path, err))
+    })?;
+    plot_svg(schema, &mut file)
+}
+
 /// A `GlobalLabel` is a custom identifier that can be assigned to
 /// multiple objects or components across the entire design.
 #[pyclass]
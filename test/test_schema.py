@@ -1,6 +1,11 @@
+import os
+import sys
+import xml.etree.ElementTree as ET
+
 import recad
 
 import unittest
+from unittest import mock
 
 class TestSchemaLoad(unittest.TestCase):
     def test_load_normal(self):
@@ -10,3 +15,89 @@ class TestSchemaLoad(unittest.TestCase):
         schema = recad.Schema.load("samples/nuco-v/nuco-v.kicad_sch")
         self.assertTrue(schema.plot(path = "/tmp/nuco-v.svg") == None)
 
+    def test_bounds(self):
+        schema = recad.Schema.load("samples/nuco-v/nuco-v.kicad_sch")
+        x, y, w, h = schema.bounds()
+        self.assertTrue(w > 0 and h > 0)
+
+    def test_bounds_drawn(self):
+        schema = recad.Schema("test-project")
+        schema.move_to((50.8, 50.8))
+        schema = schema + recad.Symbol("R1", "100k", "Device:R")
+        x, y, w, h = schema.bounds()
+        self.assertTrue(w > 0 and h > 0)
+        self.assertTrue(x <= 50.8 <= x + w)
+        self.assertTrue(y <= 50.8 <= y + h)
+        self.assertAlmostEqual(x, 50.8, delta=10.0)
+        self.assertAlmostEqual(y, 50.8, delta=10.0)
+
+    def test_bounds_empty(self):
+        bounds = recad.Schema("test-project").bounds()
+        self.assertEqual(len(bounds), 4)
+
+    def test_load_missing(self):
+        with self.assertRaises(IOError) as ctx:
+            recad.Schema.load("samples/missing.kicad_sch")
+        prefix = "unable to load schema file 'samples/missing.kicad_sch': "
+        message = str(ctx.exception)
+        self.assertTrue(message.startswith(prefix))
+        self.assertRegex(message[len(prefix):], r"(?i)no such file|os error 2")
+
+
+class TestConvert(unittest.TestCase):
+    def test_convert_svg(self):
+        recad.convert("samples/nuco-v/nuco-v.kicad_sch", "/tmp/nuco-v-convert.svg")
+        self.assertTrue(os.path.getsize("/tmp/nuco-v-convert.svg") > 0)
+        root = ET.parse("/tmp/nuco-v-convert.svg").getroot()
+        self.assertEqual(root.tag, "{http://www.w3.org/2000/svg}svg")
+
+    def test_convert_extension_case(self):
+        recad.convert("samples/nuco-v/nuco-v.kicad_sch", "/tmp/nuco-v-convert.SVG")
+        self.assertTrue(os.path.getsize("/tmp/nuco-v-convert.SVG") > 0)
+
+    def test_convert_unsupported(self):
+        with self.assertRaises(ValueError):
+            recad.convert("samples/nuco-v/nuco-v.kicad_sch", "/tmp/nuco-v.xyz")
+
+    def test_convert_unsupported_input(self):
+        with self.assertRaises(ValueError):
+            recad.convert("samples/nuco-v/nuco-v.kicad_pcb", "/tmp/nuco-v.svg")
+
+    def test_convert_missing_input(self):
+        with self.assertRaises(IOError):
+            recad.convert("samples/missing.kicad_sch", "/tmp/missing.svg")
+
+    def run_main(self, *args):
+        with mock.patch.object(sys, "argv", ["recad", *args]):
+            return recad.main()
+
+    def test_main_help(self):
+        self.assertIsNone(self.run_main("--help"))
+
+    def test_main_no_arguments(self):
+        with self.assertRaises(SystemExit) as ctx:
+            self.run_main()
+        self.assertEqual(ctx.exception.code, 2)
+
+    def test_main_convert_missing_arguments(self):
+        with self.assertRaises(SystemExit) as ctx:
+            self.run_main("convert", "samples/nuco-v/nuco-v.kicad_sch")
+        self.assertEqual(ctx.exception.code, 2)
+
+    def test_main_unknown_command(self):
+        with self.assertRaises(SystemExit) as ctx:
+            self.run_main("plot")
+        self.assertEqual(ctx.exception.code, 2)
+
+    def test_main_convert_missing_input(self):
+        with self.assertRaises(SystemExit) as ctx:
+            self.run_main("convert", "samples/missing.kicad_sch", "/tmp/missing.svg")
+        self.assertEqual(ctx.exception.code, 1)
+
+    def test_main_convert(self):
+        if os.path.exists("/tmp/nuco-v-main.svg"):
+            os.remove("/tmp/nuco-v-main.svg")
+        self.assertIsNone(
+            self.run_main("convert", "samples/nuco-v/nuco-v.kicad_sch", "/tmp/nuco-v-main.svg")
+        )
+        self.assertTrue(os.path.getsize("/tmp/nuco-v-main.svg") > 0)